@@ -1,7 +1,7 @@
 use crossterm::{
     event::{
-        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, MouseButton,
-        MouseEvent, MouseEventKind,
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind,
+        KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
     },
     execute,
     terminal::{
@@ -12,17 +12,111 @@ use crossterm::{
 use ratatui::{prelude::*, widgets::*};
 use serde::{Deserialize, Serialize};
 use serde_json;
+use std::fs;
+#[cfg(windows)]
 use std::path::Path;
+use std::path::PathBuf;
 use std::{error::Error, io};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+#[cfg(windows)]
 use winreg::enums::*;
+#[cfg(windows)]
 use winreg::RegKey;
+
+/// A persistence backend for the todo list. `RegistryStore` is the original
+/// Windows-only implementation; `FileStore` keeps the binary portable.
+trait Store {
+    fn load(&self) -> io::Result<Vec<Todo>>;
+    fn save(&self, todos: &[Todo]) -> io::Result<()>;
+}
+
+#[cfg(windows)]
+struct RegistryStore {
+    key: RegKey,
+}
+
+#[cfg(windows)]
+impl RegistryStore {
+    fn new() -> io::Result<RegistryStore> {
+        let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+        let path = Path::new("SOFTWARE").join("todolist");
+        let (key, _disp) = hkcu.create_subkey_with_flags(&path, KEY_ALL_ACCESS)?;
+        Ok(RegistryStore { key })
+    }
+}
+
+#[cfg(windows)]
+impl Store for RegistryStore {
+    fn load(&self) -> io::Result<Vec<Todo>> {
+        let todos: String = match self.key.get_value::<String, _>("todos") {
+            Ok(value) => value,
+            Err(_) => {
+                self.key.set_value("todos", &"[]")?;
+                self.key.get_value("todos")?
+            }
+        };
+        serde_json::from_str(&todos).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn save(&self, todos: &[Todo]) -> io::Result<()> {
+        let json = serde_json::to_string(todos)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        self.key.set_value("todos", &json)
+    }
+}
+
+struct FileStore {
+    path: PathBuf,
+}
+
+impl FileStore {
+    fn new() -> io::Result<FileStore> {
+        let mut dir = dirs::config_dir()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+        dir.push("todolist");
+        fs::create_dir_all(&dir)?;
+        let path = dir.join("todos.json");
+        if !path.exists() {
+            fs::write(&path, "[]")?;
+        }
+        Ok(FileStore { path })
+    }
+}
+
+impl Store for FileStore {
+    fn load(&self) -> io::Result<Vec<Todo>> {
+        let data = fs::read_to_string(&self.path)?;
+        serde_json::from_str(&data).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn save(&self, todos: &[Todo]) -> io::Result<()> {
+        let json = serde_json::to_string(todos)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(&self.path, json)
+    }
+}
+
+/// Picks the storage backend: the Windows registry by default on Windows,
+/// or the `TODOLIST_STORE=file` env var to force the portable JSON file
+/// (the only option on non-Windows targets).
+fn build_store() -> io::Result<Box<dyn Store>> {
+    #[cfg(windows)]
+    {
+        if std::env::var("TODOLIST_STORE").as_deref() != Ok("file") {
+            return Ok(Box::new(RegistryStore::new()?));
+        }
+    }
+    Ok(Box::new(FileStore::new()?))
+}
+
 enum InputMode {
     Normal,
     Editing,
     Updating,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 struct Todo {
     id: usize,
     text: String,
@@ -33,6 +127,13 @@ struct Editing {
     edit: bool,
 }
 
+enum Action {
+    Add(Todo),
+    Delete { index: usize, todo: Todo },
+    Edit { id: usize, before: String, after: String },
+    Toggle { id: usize },
+}
+
 struct App {
     input: String,
     cursor_position: usize,
@@ -40,6 +141,17 @@ struct App {
     count: usize,
     todos: Vec<Todo>,
     editing: Vec<Editing>,
+    editing_before: String,
+    undo_stack: Vec<Action>,
+    redo_stack: Vec<Action>,
+    selected: Option<usize>,
+    update_buffer: String,
+    update_cursor: usize,
+    scroll_offset: usize,
+    status_message: String,
+    status_message_time: std::time::Instant,
+    quit_confirm: bool,
+    dirty: bool,
 }
 
 impl Default for App {
@@ -51,41 +163,118 @@ impl Default for App {
             count: 0,
             todos: Vec::new(),
             editing: Vec::new(),
+            editing_before: String::new(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            selected: None,
+            update_buffer: String::new(),
+            update_cursor: 0,
+            scroll_offset: 0,
+            status_message: String::new(),
+            status_message_time: std::time::Instant::now(),
+            quit_confirm: false,
+            dirty: false,
         }
     }
 }
 
+fn grapheme_count(buffer: &str) -> usize {
+    buffer.graphemes(true).count()
+}
+
+/// Converts a grapheme index (what the cursor is counted in) into the byte
+/// offset `String::insert`/slicing needs.
+fn byte_index(buffer: &str, grapheme_idx: usize) -> usize {
+    buffer
+        .grapheme_indices(true)
+        .nth(grapheme_idx)
+        .map(|(i, _)| i)
+        .unwrap_or(buffer.len())
+}
+
+fn clamp_cursor(buffer: &str, new_cursor_pos: usize) -> usize {
+    new_cursor_pos.clamp(0, grapheme_count(buffer))
+}
+
+/// How many three-row todo slots fit below the input box and above the
+/// status bar for a terminal of the given height.
+///
+/// The budget reserves 1 row for the top spacer, 3 rows for the "Add
+/// Todo" input box, and 1 row for the status bar, on top of the 3 rows
+/// each todo slot takes.
+fn visible_rows_for(height: u16) -> usize {
+    (height as usize).saturating_sub(5) / 3
+}
+
+const STATUS_MESSAGE_SECS: u64 = 5;
+
 impl App {
+    fn set_status(&mut self, message: impl Into<String>) {
+        self.status_message = message.into();
+        self.status_message_time = std::time::Instant::now();
+    }
+
+    /// Clears the status message (and any pending quit confirmation) once
+    /// it has been on screen for `STATUS_MESSAGE_SECS`.
+    fn tick_status(&mut self) {
+        if !self.status_message.is_empty()
+            && self.status_message_time.elapsed()
+                > std::time::Duration::from_secs(STATUS_MESSAGE_SECS)
+        {
+            self.status_message.clear();
+            self.quit_confirm = false;
+        }
+    }
+
+    /// Returns the text buffer and cursor position that the current
+    /// `input_mode` is editing, so cursor movement/editing code doesn't
+    /// need to know whether it's working on the "Add Todo" box or an
+    /// in-place update.
+    fn active_buffer_mut(&mut self) -> (&mut String, &mut usize) {
+        match self.input_mode {
+            InputMode::Updating => (&mut self.update_buffer, &mut self.update_cursor),
+            _ => (&mut self.input, &mut self.cursor_position),
+        }
+    }
+
     fn move_cursor_left(&mut self) {
-        let cursor_moved_left = self.cursor_position.saturating_sub(1);
-        self.cursor_position = self.clamp_cursor(cursor_moved_left);
+        let (buffer, pos) = self.active_buffer_mut();
+        *pos = clamp_cursor(buffer, pos.saturating_sub(1));
     }
 
     fn move_cursor_right(&mut self) {
-        let cursor_moved_right = self.cursor_position.saturating_add(1);
-        self.cursor_position = self.clamp_cursor(cursor_moved_right);
+        let (buffer, pos) = self.active_buffer_mut();
+        *pos = clamp_cursor(buffer, pos.saturating_add(1));
     }
 
     fn enter_char(&mut self, new_char: char) {
-        self.input.insert(self.cursor_position, new_char);
-
-        self.move_cursor_right();
+        let (buffer, pos) = self.active_buffer_mut();
+        let byte_idx = byte_index(buffer, *pos);
+        buffer.insert(byte_idx, new_char);
+        *pos = clamp_cursor(buffer, *pos + 1);
     }
 
     fn delete_char(&mut self) {
-        let is_not_cursor_leftmost = self.cursor_position != 0;
-        if is_not_cursor_leftmost {
-            let current_index = self.cursor_position;
+        let (buffer, pos) = self.active_buffer_mut();
+        if *pos != 0 {
+            let current_index = *pos;
             let from_left_to_current_index = current_index - 1;
-            let before_char_to_delete = self.input.chars().take(from_left_to_current_index);
-            let after_char_to_delete = self.input.chars().skip(current_index);
-            self.input = before_char_to_delete.chain(after_char_to_delete).collect();
-            self.move_cursor_left();
+            let before_char_to_delete: String =
+                buffer.graphemes(true).take(from_left_to_current_index).collect();
+            let after_char_to_delete: String =
+                buffer.graphemes(true).skip(current_index).collect();
+            *buffer = before_char_to_delete + &after_char_to_delete;
+            *pos = clamp_cursor(buffer, current_index - 1);
         }
     }
 
-    fn clamp_cursor(&self, new_cursor_pos: usize) -> usize {
-        new_cursor_pos.clamp(0, self.input.len())
+    fn delete_char_forward(&mut self) {
+        let (buffer, pos) = self.active_buffer_mut();
+        if *pos < grapheme_count(buffer) {
+            let before_char_to_delete: String = buffer.graphemes(true).take(*pos).collect();
+            let after_char_to_delete: String = buffer.graphemes(true).skip(*pos + 1).collect();
+            *buffer = before_char_to_delete + &after_char_to_delete;
+        }
     }
 
     fn reset_cursor(&mut self) {
@@ -100,10 +289,200 @@ impl App {
         };
         let edit = Editing { edit: false };
         self.editing.push(edit);
-        self.todos.insert(self.count, todo);
+        self.todos.insert(self.count, todo.clone());
         self.input.clear();
         self.reset_cursor();
         self.count += 1;
+        self.undo_stack.push(Action::Add(todo));
+        self.redo_stack.clear();
+        self.dirty = true;
+        let saved = self.todos.len();
+        self.set_status(format!("{saved} todos saved"));
+    }
+
+    fn renumber(&mut self) {
+        let mut j = 0;
+        while j < self.todos.len() {
+            self.todos[j].id = j;
+            j += 1;
+        }
+    }
+
+    /// Keeps `selected` and `scroll_offset` pointing inside `self.todos`
+    /// after something other than the dedicated select-aware helpers
+    /// (like `delete_selected`) has resized the list.
+    fn clamp_selection(&mut self) {
+        self.selected = self.selected.filter(|&i| i < self.todos.len());
+        self.scroll_offset = self.scroll_offset.min(self.todos.len().saturating_sub(1));
+    }
+
+    fn undo(&mut self) {
+        if let Some(action) = self.undo_stack.pop() {
+            match &action {
+                Action::Add(todo) => {
+                    self.todos.retain(|t| t.id != todo.id);
+                    self.editing.pop();
+                    self.count -= 1;
+                }
+                Action::Delete { index, todo } => {
+                    self.todos.insert(*index, todo.clone());
+                    self.editing.insert(*index, Editing { edit: false });
+                    self.count += 1;
+                    self.renumber();
+                }
+                Action::Edit { id, before, .. } => {
+                    if let Some(t) = self.todos.iter_mut().find(|t| t.id == *id) {
+                        t.text = before.clone();
+                    }
+                }
+                Action::Toggle { id } => {
+                    if let Some(t) = self.todos.iter_mut().find(|t| t.id == *id) {
+                        t.done = !t.done;
+                    }
+                }
+            }
+            self.redo_stack.push(action);
+            self.clamp_selection();
+            self.dirty = true;
+            self.set_status("Undid last action");
+        }
+    }
+
+    fn redo(&mut self) {
+        if let Some(action) = self.redo_stack.pop() {
+            match &action {
+                Action::Add(todo) => {
+                    self.todos.push(todo.clone());
+                    self.editing.push(Editing { edit: false });
+                    self.count += 1;
+                }
+                Action::Delete { todo, .. } => {
+                    if let Some(pos) = self.todos.iter().position(|t| t.id == todo.id) {
+                        self.todos.remove(pos);
+                        self.editing.remove(pos);
+                        self.count -= 1;
+                        self.renumber();
+                    }
+                }
+                Action::Edit { id, after, .. } => {
+                    if let Some(t) = self.todos.iter_mut().find(|t| t.id == *id) {
+                        t.text = after.clone();
+                    }
+                }
+                Action::Toggle { id } => {
+                    if let Some(t) = self.todos.iter_mut().find(|t| t.id == *id) {
+                        t.done = !t.done;
+                    }
+                }
+            }
+            self.undo_stack.push(action);
+            self.clamp_selection();
+            self.dirty = true;
+            self.set_status("Redid last action");
+        }
+    }
+
+    fn select_next(&mut self) {
+        if self.todos.is_empty() {
+            return;
+        }
+        self.selected = Some(match self.selected {
+            Some(i) if i + 1 < self.todos.len() => i + 1,
+            Some(i) => i,
+            None => 0,
+        });
+    }
+
+    fn select_previous(&mut self) {
+        if self.todos.is_empty() {
+            return;
+        }
+        self.selected = Some(match self.selected {
+            Some(i) => i.saturating_sub(1),
+            None => 0,
+        });
+    }
+
+    fn select_first(&mut self) {
+        if !self.todos.is_empty() {
+            self.selected = Some(0);
+        }
+    }
+
+    fn select_last(&mut self) {
+        if !self.todos.is_empty() {
+            self.selected = Some(self.todos.len() - 1);
+        }
+    }
+
+    /// Keeps the selected/edited row inside the viewport, scrolling as
+    /// little as necessary.
+    fn ensure_visible(&mut self, visible_rows: usize) {
+        if visible_rows == 0 {
+            return;
+        }
+        if let Some(i) = self.selected {
+            if i < self.scroll_offset {
+                self.scroll_offset = i;
+            } else if i >= self.scroll_offset + visible_rows {
+                self.scroll_offset = i + 1 - visible_rows;
+            }
+        }
+    }
+
+    fn page_down(&mut self, visible_rows: usize) {
+        if self.todos.is_empty() {
+            return;
+        }
+        let step = visible_rows.max(1);
+        self.scroll_offset = (self.scroll_offset + step).min(self.todos.len() - 1);
+        self.selected = Some(self.scroll_offset);
+    }
+
+    fn page_up(&mut self, visible_rows: usize) {
+        if self.todos.is_empty() {
+            return;
+        }
+        let step = visible_rows.max(1);
+        self.scroll_offset = self.scroll_offset.saturating_sub(step);
+        self.selected = Some(self.scroll_offset);
+    }
+
+    fn toggle_selected(&mut self) {
+        if let Some(i) = self.selected.filter(|&i| i < self.todos.len()) {
+            self.todos[i].done = !self.todos[i].done;
+            self.undo_stack.push(Action::Toggle { id: self.todos[i].id });
+            self.redo_stack.clear();
+            self.dirty = true;
+        }
+    }
+
+    fn delete_selected(&mut self) {
+        if let Some(i) = self.selected.filter(|&i| i < self.todos.len()) {
+            let todo = self.todos.remove(i);
+            self.editing.remove(i);
+            self.count -= 1;
+            self.set_status(format!("Deleted '{}' — press u to undo", todo.text));
+            self.undo_stack.push(Action::Delete { index: i, todo });
+            self.redo_stack.clear();
+            self.dirty = true;
+            self.renumber();
+            self.selected = if self.todos.is_empty() {
+                None
+            } else {
+                Some(i.min(self.todos.len() - 1))
+            };
+        }
+    }
+
+    fn begin_editing_selected(&mut self) {
+        if let Some(i) = self.selected.filter(|&i| i < self.todos.len()) {
+            self.editing[i].edit = true;
+            self.editing_before = self.todos[i].text.clone();
+            self.update_buffer = self.todos[i].text.clone();
+            self.update_cursor = grapheme_count(&self.update_buffer);
+            self.input_mode = InputMode::Updating;
+        }
     }
 }
 
@@ -121,7 +500,8 @@ fn main() -> std::result::Result<(), Box<dyn Error>> {
     let mut terminal = Terminal::new(backend)?;
 
     let app = App::default();
-    let res = run_app(&mut terminal, app);
+    let store = build_store()?;
+    let res = run_app(&mut terminal, app, store);
 
     disable_raw_mode()?;
     execute!(
@@ -138,22 +518,12 @@ fn main() -> std::result::Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<()> {
-    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
-    let path = Path::new("SOFTWARE").join("todolist");
-    let (key, _disp) = hkcu.create_subkey_with_flags(&path, KEY_ALL_ACCESS)?;
-    let todos: String;
-
-    match key.get_value::<String, _>("todos") {
-        Ok(_) => todos = key.get_value("todos")?,
-        Err(_) => {
-            key.set_value("todos", &"[]")?;
-            todos = key.get_value("todos")?
-        }
-    }
-
-    let todo: Vec<Todo> = serde_json::from_str(&todos)?;
-    app.todos = todo;
+fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    mut app: App,
+    store: Box<dyn Store>,
+) -> io::Result<()> {
+    app.todos = store.load()?;
     app.count = app.todos.len();
     let mut i = 0;
     while i < app.count {
@@ -162,18 +532,75 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
         i += 1;
     }
     loop {
-        let json = serde_json::to_string(&app.todos)?;
-        key.set_value("todos", &json)?;
+        store.save(&app.todos)?;
+        let visible_rows = visible_rows_for(terminal.size()?.height);
+        app.ensure_visible(visible_rows);
+        app.tick_status();
         terminal.draw(|f| ui(f, &app))?;
 
         if let Event::Key(key) = event::read()? {
             match app.input_mode {
-                InputMode::Normal => match key.code {
-                    KeyCode::Esc => {
-                        return Ok(());
+                InputMode::Normal => {
+                    match key.code {
+                        KeyCode::Esc => {
+                            // tick_status() only runs once per loop iteration,
+                            // right before the next (blocking) key read, so it
+                            // never gets a chance to expire quit_confirm while
+                            // we're waiting for this very keypress — check the
+                            // elapsed time directly instead.
+                            let confirm_expired = app.quit_confirm
+                                && app.status_message_time.elapsed()
+                                    > std::time::Duration::from_secs(STATUS_MESSAGE_SECS);
+                            if app.quit_confirm && !confirm_expired {
+                                return Ok(());
+                            }
+                            if app.dirty || confirm_expired {
+                                app.quit_confirm = true;
+                                app.dirty = false;
+                                app.set_status("Press Esc again to quit");
+                            } else {
+                                return Ok(());
+                            }
+                        }
+                        KeyCode::Char('u') => {
+                            app.undo();
+                        }
+                        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            app.redo();
+                        }
+                        KeyCode::Char('j') | KeyCode::Down => {
+                            app.select_next();
+                        }
+                        KeyCode::Char('k') | KeyCode::Up => {
+                            app.select_previous();
+                        }
+                        KeyCode::Char('g') => {
+                            app.select_first();
+                        }
+                        KeyCode::Char('G') => {
+                            app.select_last();
+                        }
+                        KeyCode::PageDown => {
+                            app.page_down(visible_rows);
+                        }
+                        KeyCode::PageUp => {
+                            app.page_up(visible_rows);
+                        }
+                        KeyCode::Char(' ') | KeyCode::Char('x') => {
+                            app.toggle_selected();
+                        }
+                        KeyCode::Char('d') => {
+                            app.delete_selected();
+                        }
+                        KeyCode::Char('e') | KeyCode::Enter => {
+                            app.begin_editing_selected();
+                        }
+                        _ => {}
                     }
-                    _ => {}
-                },
+                    if key.code != KeyCode::Esc {
+                        app.quit_confirm = false;
+                    }
+                }
                 InputMode::Editing if key.kind == KeyEventKind::Press => match key.code {
                     KeyCode::Enter => app.submit_message(),
                     KeyCode::Char(to_insert) => {
@@ -197,28 +624,34 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
                         while i < app.todos.len() {
                             if app.editing[i].edit {
                                 app.editing[i].edit = false;
+                                if app.update_buffer != app.editing_before {
+                                    app.undo_stack.push(Action::Edit {
+                                        id: app.todos[i].id,
+                                        before: app.editing_before.clone(),
+                                        after: app.update_buffer.clone(),
+                                    });
+                                    app.redo_stack.clear();
+                                    app.dirty = true;
+                                }
+                                app.todos[i].text = app.update_buffer.clone();
                             }
                             i += 1;
                         }
                     }
                     KeyCode::Char(to_insert) => {
-                        let mut i = 0;
-                        while i < app.todos.len() {
-                            if app.editing[i].edit {
-                                let len = app.todos[i].text.len();
-                                app.todos[i].text.insert((len) as usize, to_insert);
-                            }
-                            i += 1;
-                        }
+                        app.enter_char(to_insert);
                     }
                     KeyCode::Backspace => {
-                        let mut i = 0;
-                        while i < app.todos.len() {
-                            if app.editing[i].edit {
-                                app.todos[i].text.pop();
-                            }
-                            i += 1;
-                        }
+                        app.delete_char();
+                    }
+                    KeyCode::Delete => {
+                        app.delete_char_forward();
+                    }
+                    KeyCode::Left => {
+                        app.move_cursor_left();
+                    }
+                    KeyCode::Right => {
+                        app.move_cursor_right();
                     }
                     _ => {}
                 },
@@ -240,49 +673,41 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
                         } else {
                             app.input_mode = InputMode::Normal;
                         }
-                        let mut i = 0;
-                        let mut rw = 0;
-                        while i < app.todos.len() {
-                            if i == 0 {
-                                if (column == 3 || column == 4) && row == i as u16 + 5 {
-                                    app.todos[i].done = !app.todos[i].done;
-                                } else if column == 56 && row == i as u16 + 5 {
-                                    app.todos.remove(i);
-                                    app.count -= 1;
-                                    let mut j = 0;
-                                    while j < app.todos.len() {
-                                        app.todos[j].id = j;
-                                        j += 1;
-                                    }
-                                } else if column > 5 && column < 56 && row == i as u16 + 5 {
-                                    app.editing[i].edit = true;
-                                    app.input_mode = InputMode::Updating;
-                                } else {
-                                    app.editing[i].edit = false;
+                        let mut i = app.scroll_offset;
+                        while i < app.todos.len() && i < app.scroll_offset + visible_rows {
+                            let rw = ((i - app.scroll_offset) as u16) * 3;
+                            if (column == 3 || column == 4) && row == 5 + rw {
+                                app.todos[i].done = !app.todos[i].done;
+                                app.undo_stack.push(Action::Toggle { id: app.todos[i].id });
+                                app.redo_stack.clear();
+                                app.dirty = true;
+                            } else if column == 56 && row == 5 + rw {
+                                let todo = app.todos.remove(i);
+                                app.editing.remove(i);
+                                app.count -= 1;
+                                app.set_status(format!("Deleted '{}' — press u to undo", todo.text));
+                                app.undo_stack.push(Action::Delete { index: i, todo });
+                                app.redo_stack.clear();
+                                app.dirty = true;
+                                let mut j = 0;
+                                while j < app.todos.len() {
+                                    app.todos[j].id = j;
+                                    j += 1;
                                 }
+                                app.clamp_selection();
+                            } else if column > 5 && column < 56 && row == 5 + rw {
+                                app.editing[i].edit = true;
+                                app.editing_before = app.todos[i].text.clone();
+                                app.update_buffer = app.todos[i].text.clone();
+                                app.update_cursor = grapheme_count(&app.update_buffer);
+                                app.selected = Some(i);
+                                app.input_mode = InputMode::Updating;
                             } else {
-                                if (column == 3 || column == 4) && row == 5 + rw {
-                                    app.todos[i].done = !app.todos[i].done;
-                                } else if column == 56 && row == 5 + rw {
-                                    app.todos.remove(i);
-                                    app.count -= 1;
-                                    let mut j = 0;
-                                    while j < app.todos.len() {
-                                        app.todos[j].id = j;
-                                        j += 1;
-                                    }
-                                } else if column > 5 && column < 56 && row == 5 + rw {
-                                    app.editing[i].edit = true;
-                                    app.input_mode = InputMode::Updating;
-                                } else {
-                                    app.editing[i].edit = false;
-                                }
+                                app.editing[i].edit = false;
                             }
                             i += 1;
-                            rw += 3;
                         }
-                        let json = serde_json::to_string(&app.todos)?;
-                        key.set_value("todos", &json)?;
+                        store.save(&app.todos)?;
                     }
                 }
             }
@@ -291,12 +716,15 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<(
 }
 
 fn ui<B: Backend>(f: &mut Frame<B>, app: &App) {
+    let visible_rows = visible_rows_for(f.size().height);
+
     let mut constraints = Vec::new();
     constraints.push(Constraint::Length(1));
-    for _ in 0..17 {
+    constraints.push(Constraint::Length(3)); // input box
+    for _ in 0..visible_rows {
         constraints.push(Constraint::Length(3));
     }
-    constraints.push(Constraint::Min(1));
+    constraints.push(Constraint::Min(1)); // status bar
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -321,29 +749,44 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &App) {
     match app.input_mode {
         InputMode::Normal => {}
         InputMode::Updating => {}
-        InputMode::Editing => f.set_cursor(
-            chunks[1].x + app.cursor_position as u16 + 2,
-            chunks[1].y + 1,
-        ),
+        InputMode::Editing => {
+            let prefix: String = app.input.graphemes(true).take(app.cursor_position).collect();
+            f.set_cursor(
+                chunks[1].x + UnicodeWidthStr::width(prefix.as_str()) as u16 + 2,
+                chunks[1].y + 1,
+            )
+        }
     }
 
-    for todo in app.todos.iter() {
+    for (slot, todo) in app
+        .todos
+        .iter()
+        .enumerate()
+        .skip(app.scroll_offset)
+        .take(visible_rows)
+        .map(|(i, todo)| (i - app.scroll_offset, todo))
+    {
         let t: &Todo = todo;
-        let mut i = 0;
+        let display_text = if app.editing[t.id].edit {
+            &app.update_buffer
+        } else {
+            &t.text
+        };
         let mut space = String::new();
-        while i < 48 - t.text.len() {
+        for _ in 0..48usize.saturating_sub(UnicodeWidthStr::width(display_text.as_str())) {
             space += " ";
-            i += 1;
         }
 
         f.render_widget(
             Paragraph::new(if t.done {
-                "[./] ".to_owned() + &t.text.to_string() + &space + "[x] "
+                "[./] ".to_owned() + display_text + &space + "[x] "
             } else {
-                "[  ] ".to_owned() + &t.text.to_string() + &space + "[x] "
+                "[  ] ".to_owned() + display_text + &space + "[x] "
             })
             .style(if app.editing[t.id].edit {
                 Style::default().fg(Color::Yellow)
+            } else if app.selected == Some(t.id) {
+                Style::default().add_modifier(Modifier::REVERSED)
             } else {
                 Style::default()
             })
@@ -353,14 +796,26 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &App) {
                     .add_modifier(Modifier::BOLD)
                     .padding(Padding::new(1, 0, 0, 0)),
             ),
-            chunks[2 + t.id],
+            chunks[2 + slot],
         );
 
         if app.editing[t.id].edit {
+            let prefix: String = app
+                .update_buffer
+                .graphemes(true)
+                .take(app.update_cursor)
+                .collect();
             f.set_cursor(
-                chunks[2 + t.id].x + t.text.len() as u16 + 7,
-                chunks[2 + t.id].y + 1,
+                chunks[2 + slot].x + UnicodeWidthStr::width(prefix.as_str()) as u16 + 7,
+                chunks[2 + slot].y + 1,
             );
         }
     }
+
+    if !app.status_message.is_empty() {
+        f.render_widget(
+            Paragraph::new(app.status_message.as_str()).style(Style::default().fg(Color::Gray)),
+            chunks[chunks.len() - 1],
+        );
+    }
 }